@@ -1,34 +1,323 @@
 use dioxus::prelude::*;
 use gloo_timers::callback::Interval;
+use serde::{Deserialize, Serialize};
 
 // ポモドーロタイマーの状態
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 enum TimerMode {
     Work,
     ShortBreak,
     LongBreak,
 }
 
+// 完了した1セッション分の記録
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SessionRecord {
+    mode: TimerMode,
+    // ミリ秒単位の Unix タイムスタンプ(js_sys::Date::now())
+    started_at: f64,
+    ended_at: f64,
+}
+
+// 履歴パネルの絞り込み
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HistoryFilter {
+    Today,
+    AllTime,
+}
+
+// セッション終了後の挙動
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum AutoContinueMode {
+    // 確認なしで次のセッションを開始する
+    AutoContinue,
+    // 開始してよいか確認してから次のセッションに進む
+    Confirm,
+}
+
 // ポモドーロタイマーの設定
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct TimerSettings {
-    work_minutes: u32,
-    short_break_minutes: u32,
-    long_break_minutes: u32,
+    // 各時間は秒単位で保持する("1h30m" のような柔軟な入力をパースして格納する)
+    work_seconds: u32,
+    short_break_seconds: u32,
+    long_break_seconds: u32,
     sessions_before_long_break: u32,
+    // セッション終了時にブラウザ通知を出すかどうか
+    notifications_enabled: bool,
+    // セッション終了時にチャイム音を鳴らすかどうか
+    sound_enabled: bool,
+    // セッション終了の何分前に予告通知を出すか(0 で無効)
+    notify_before_minutes: u32,
+    // セッション終了後の挙動(自動継続 or 確認)
+    auto_continue_mode: AutoContinueMode,
 }
 
 // デフォルト設定
 impl Default for TimerSettings {
     fn default() -> Self {
         Self {
-            work_minutes: 25,
-            short_break_minutes: 5,
-            long_break_minutes: 15,
+            work_seconds: 25 * 60,
+            short_break_seconds: 5 * 60,
+            long_break_seconds: 15 * 60,
             sessions_before_long_break: 4,
+            notifications_enabled: true,
+            sound_enabled: true,
+            notify_before_minutes: 0,
+            auto_continue_mode: AutoContinueMode::Confirm,
+        }
+    }
+}
+
+// 設定を保存する localStorage のキー
+const SETTINGS_STORAGE_KEY: &str = "pomodoro_timer_settings";
+
+// localStorage から設定を読み込む。存在しない・壊れている場合はデフォルトにフォールバック
+fn load_settings() -> TimerSettings {
+    let mut settings: TimerSettings = web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(SETTINGS_STORAGE_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+
+    // 旧バージョンが保存した 0 など不正な値から周期計算を守る
+    if settings.sessions_before_long_break < 1 {
+        settings.sessions_before_long_break = 1;
+    }
+
+    settings
+}
+
+// 設定を localStorage に保存する
+fn save_settings(settings: &TimerSettings) {
+    let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+    else {
+        return;
+    };
+    if let Ok(json) = serde_json::to_string(settings) {
+        let _ = storage.set_item(SETTINGS_STORAGE_KEY, &json);
+    }
+}
+
+// セッション履歴を保存する localStorage のキー
+const HISTORY_STORAGE_KEY: &str = "pomodoro_timer_history";
+
+// localStorage からセッション履歴を読み込む。存在しない・壊れている場合は空の履歴にフォールバック
+fn load_history() -> Vec<SessionRecord> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(HISTORY_STORAGE_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+// セッション履歴を localStorage に保存する
+fn save_history(history: &[SessionRecord]) {
+    let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+    else {
+        return;
+    };
+    if let Ok(json) = serde_json::to_string(history) {
+        let _ = storage.set_item(HISTORY_STORAGE_KEY, &json);
+    }
+}
+
+// タイマーの実行状態。リロードをまたいで残り時間を復元するために永続化する
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TimerRuntimeState {
+    mode: TimerMode,
+    is_active: bool,
+    // 実行中の場合のみ設定される、現在のセッションが終了する壁時計上の時刻(ミリ秒)
+    end_instant: Option<f64>,
+    // 一時停止中も含めて常に最新の残り時間(秒)
+    seconds_remaining: u32,
+}
+
+// タイマーの実行状態を保存する localStorage のキー
+const RUNTIME_STATE_STORAGE_KEY: &str = "pomodoro_timer_runtime_state";
+
+// localStorage からタイマーの実行状態を読み込む。存在しない・壊れている場合は None
+fn load_runtime_state() -> Option<TimerRuntimeState> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(RUNTIME_STATE_STORAGE_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+// タイマーの実行状態を localStorage に保存する
+fn save_runtime_state(state: &TimerRuntimeState) {
+    let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+    else {
+        return;
+    };
+    if let Ok(json) = serde_json::to_string(state) {
+        let _ = storage.set_item(RUNTIME_STATE_STORAGE_KEY, &json);
+    }
+}
+
+// 指定したタイムスタンプ(ミリ秒)が今日の日付かどうか
+fn is_today(timestamp_ms: f64) -> bool {
+    let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(timestamp_ms));
+    let now = js_sys::Date::new_0();
+    date.get_full_year() == now.get_full_year()
+        && date.get_month() == now.get_month()
+        && date.get_date() == now.get_date()
+}
+
+// 現在のモードに応じた設定時間(秒)を取得する
+fn duration_for_mode(settings: &TimerSettings, mode: &TimerMode) -> u32 {
+    match mode {
+        TimerMode::Work => settings.work_seconds,
+        TimerMode::ShortBreak => settings.short_break_seconds,
+        TimerMode::LongBreak => settings.long_break_seconds,
+    }
+}
+
+// "1h30m" / "90s" / "25m" のような時間指定文字列を秒に変換する。
+// 単位のないプレーンな数値は分として解釈する
+fn parse_duration(input: &str) -> Result<u32, String> {
+    let trimmed = input.trim();
+    let invalid = || format!("\"{}\" を時間として解釈できません", input);
+
+    if trimmed.is_empty() {
+        return Err(invalid());
+    }
+
+    if let Ok(minutes) = trimmed.parse::<u32>() {
+        if minutes == 0 {
+            return Err(invalid());
+        }
+        return Ok(minutes * 60);
+    }
+
+    let mut total_seconds: u32 = 0;
+    let mut number = String::new();
+    let mut parsed_any_unit = false;
+
+    for ch in trimmed.chars() {
+        if ch.is_ascii_digit() {
+            number.push(ch);
+            continue;
+        }
+
+        let unit_seconds = match ch {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(invalid()),
+        };
+        if number.is_empty() {
+            return Err(invalid());
         }
+
+        let value: u32 = number.parse().map_err(|_| invalid())?;
+        total_seconds = total_seconds.saturating_add(value * unit_seconds);
+        number.clear();
+        parsed_any_unit = true;
+    }
+
+    if !number.is_empty() || !parsed_any_unit || total_seconds == 0 {
+        return Err(invalid());
+    }
+
+    Ok(total_seconds)
+}
+
+// 秒を "1h30m" のような時間指定文字列に戻す(入力欄の初期表示に使う)
+fn format_duration(total_seconds: u32) -> String {
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+
+    let mut formatted = String::new();
+    if hours > 0 {
+        formatted.push_str(&format!("{}h", hours));
+    }
+    if minutes > 0 {
+        formatted.push_str(&format!("{}m", minutes));
+    }
+    if seconds > 0 || formatted.is_empty() {
+        formatted.push_str(&format!("{}s", seconds));
+    }
+    formatted
+}
+
+// 終了したモードに応じた通知メッセージ
+fn session_end_message(ended_mode: &TimerMode) -> &'static str {
+    match ended_mode {
+        TimerMode::Work => "仕事が終わりました",
+        TimerMode::ShortBreak | TimerMode::LongBreak => "休憩が終わりました",
+    }
+}
+
+// ブラウザ通知を送る。通知 API が使えない・許可されていない場合は何もしない
+fn send_browser_notification(body: &str) {
+    let has_notification_api = web_sys::window()
+        .map(|window| {
+            js_sys::Reflect::has(&window, &wasm_bindgen::JsValue::from_str("Notification"))
+                .unwrap_or(false)
+        })
+        .unwrap_or(false);
+    if !has_notification_api {
+        return;
+    }
+    if web_sys::Notification::permission() != web_sys::NotificationPermission::Granted {
+        return;
+    }
+    let options = web_sys::NotificationOptions::new();
+    options.set_body(body);
+    let _ = web_sys::Notification::new_with_options("ポモドーロタイマー", &options);
+}
+
+// Web Audio API で短いチャイム音を鳴らす
+fn play_chime() {
+    let Ok(ctx) = web_sys::AudioContext::new() else {
+        return;
+    };
+    let Ok(oscillator) = ctx.create_oscillator() else {
+        return;
+    };
+    let Ok(gain) = ctx.create_gain() else {
+        return;
+    };
+    oscillator.set_type(web_sys::OscillatorType::Sine);
+    oscillator.frequency().set_value(880.0);
+    if oscillator.connect_with_audio_node(&gain).is_err() {
+        return;
+    }
+    if gain.connect_with_audio_node(&ctx.destination()).is_err() {
+        return;
+    }
+    let end_time = ctx.current_time() + 0.3;
+    let _ = oscillator.start();
+    let _ = oscillator.stop_with_when(end_time);
+}
+
+// セッション終了時の通知・チャイムをまとめて鳴らす
+fn announce_session_end(settings: &TimerSettings, ended_mode: &TimerMode) {
+    if settings.notifications_enabled {
+        send_browser_notification(session_end_message(ended_mode));
+    }
+    if settings.sound_enabled {
+        play_chime();
     }
 }
 
+// モードに応じたラベル(通知メッセージの組み立てに使う)
+fn mode_label(mode: &TimerMode) -> &'static str {
+    match mode {
+        TimerMode::Work => "仕事",
+        TimerMode::ShortBreak => "短い休憩",
+        TimerMode::LongBreak => "長い休憩",
+    }
+}
+
+// 終了予告通知を送る
+fn send_pre_warning_notification(mode: &TimerMode, minutes: u32) {
+    let body = format!("{}終了まであと{}分です", mode_label(mode), minutes);
+    send_browser_notification(&body);
+}
+
 #[derive(Debug, Clone, Routable, PartialEq)]
 #[rustfmt::skip]
 enum Route {
@@ -55,76 +344,200 @@ fn App() -> Element {
 
 #[component]
 pub fn PomodoroTimer() -> Element {
-    let settings = use_signal(|| TimerSettings::default());
-    let mut mode = use_signal(|| TimerMode::Work);
-    let mut seconds_remaining = use_signal(|| settings.with(|s| s.work_minutes) * 60);
-    let mut is_active = use_signal(|| false);
+    // リロードをまたいだ状態復元のため、シグナルを構築する前に一度だけ読み込んでおく
+    let initial_runtime_state = load_runtime_state();
+
+    let mut settings = use_signal(load_settings);
+    let mut mode = use_signal(|| {
+        initial_runtime_state
+            .as_ref()
+            .map(|s| s.mode.clone())
+            .unwrap_or(TimerMode::Work)
+    });
+    let mut seconds_remaining = use_signal(|| {
+        initial_runtime_state
+            .as_ref()
+            .map(|s| s.seconds_remaining)
+            .unwrap_or_else(|| settings.with(|s| duration_for_mode(s, &mode())))
+    });
+    let mut is_active = use_signal(|| {
+        initial_runtime_state
+            .as_ref()
+            .map(|s| s.is_active)
+            .unwrap_or(false)
+    });
     let sessions_completed = use_signal(|| 0);
     let mut interval = use_signal(|| None::<Interval>);
+    let mut show_settings = use_signal(|| false);
+    // 終了予告通知を今回のセッションで既に出したか
+    let mut pre_warning_sent = use_signal(|| false);
+    // 「確認」モードで次のセッション開始を待っているか
+    let mut awaiting_confirmation = use_signal(|| false);
+    // セッション履歴(localStorage に永続化される)
+    let mut history = use_signal(load_history);
+    // 現在のセッションが開始した時刻(ミリ秒)。一時停止をまたいで保持する
+    let mut session_started_at = use_signal(|| None::<f64>);
+    // 実行中のセッションが終了する壁時計上の時刻(ミリ秒)。ドリフトやタブのスロットリングの影響を受けない
+    let mut end_instant = use_signal(|| initial_runtime_state.and_then(|s| s.end_instant));
+
+    // 現在の実行状態を localStorage に書き出す
+    let persist_runtime = move || {
+        save_runtime_state(&TimerRuntimeState {
+            mode: mode(),
+            is_active: is_active(),
+            end_instant: end_instant(),
+            seconds_remaining: seconds_remaining(),
+        });
+    };
+
+    // 初回マウント時に通知の許可をリクエストする
+    use_effect(move || {
+        if web_sys::Notification::permission() == web_sys::NotificationPermission::Default {
+            let _ = web_sys::Notification::request_permission();
+        }
+    });
+
+    // 1秒ごとに残り時間を壁時計から再計算してインターバルを張る
+    let mut spawn_interval = move || {
+        // 既存のインターバルがあれば解除
+        if interval.with(|i| i.is_some()) {
+            interval.set(None);
+        }
+
+        let timer_callback = {
+            let mut seconds_remaining = seconds_remaining.clone();
+            let mut is_active = is_active.clone();
+            let mut mode = mode.clone();
+            let mut sessions_completed = sessions_completed.clone();
+            let settings = settings.clone();
+            let mut interval = interval.clone();
+            let mut pre_warning_sent = pre_warning_sent.clone();
+            let mut awaiting_confirmation = awaiting_confirmation.clone();
+            let mut history = history.clone();
+            let mut session_started_at = session_started_at.clone();
+            let mut end_instant = end_instant.clone();
+            let persist_runtime = persist_runtime.clone();
+
+            move || {
+                let now = js_sys::Date::now();
+                let remaining_secs = end_instant()
+                    .map(|target| ((target - now) / 1000.0).ceil())
+                    .unwrap_or(0.0);
+
+                if remaining_secs > 0.0 {
+                    seconds_remaining.set(remaining_secs as u32);
+
+                    // 終了予告通知: バックグラウンドタブのスロットリングで閾値ちょうどを
+                    // 取りこぼすことがあるため、閾値以下に入った最初のタイミングで一度だけ送る。
+                    // ただしセッション時間以上の閾値では「予告」にならないため送らない
+                    let warning_threshold = settings.with(|s| s.notify_before_minutes) * 60;
+                    let session_duration = settings.with(|s| duration_for_mode(s, &mode()));
+                    if warning_threshold > 0
+                        && warning_threshold < session_duration
+                        && !pre_warning_sent()
+                        && remaining_secs as u32 <= warning_threshold
+                    {
+                        settings.with(|s| {
+                            send_pre_warning_notification(&mode(), s.notify_before_minutes)
+                        });
+                        pre_warning_sent.set(true);
+                    }
+
+                    persist_runtime();
+                    return;
+                }
+
+                // タイマー終了時の処理
+                seconds_remaining.set(0);
+
+                // 終了したセッションを通知・チャイムで知らせる
+                settings.with(|s| announce_session_end(s, &mode()));
+
+                // このセッションを履歴に記録する
+                let ended_at = now;
+                let started_at = session_started_at().unwrap_or(ended_at);
+                history.with_mut(|records| {
+                    records.push(SessionRecord {
+                        mode: mode(),
+                        started_at,
+                        ended_at,
+                    })
+                });
+                save_history(&history());
+
+                // 次のモードに切り替え
+                match mode() {
+                    TimerMode::Work => {
+                        let current_sessions = sessions_completed() + 1;
+                        sessions_completed.set(current_sessions);
+
+                        // 長い休憩の条件を満たしているかチェック
+                        if current_sessions % settings.with(|s| s.sessions_before_long_break) == 0
+                        {
+                            mode.set(TimerMode::LongBreak);
+                        } else {
+                            mode.set(TimerMode::ShortBreak);
+                        }
+                    }
+                    TimerMode::ShortBreak | TimerMode::LongBreak => {
+                        mode.set(TimerMode::Work);
+                    }
+                }
+                let new_duration = settings.with(|s| duration_for_mode(s, &mode()));
+                seconds_remaining.set(new_duration);
+
+                // 次のセッションに向けて予告通知のフラグを再アーム
+                pre_warning_sent.set(false);
+
+                // 自動継続でなければインターバルを止めて確認待ちにする
+                let auto_continue =
+                    settings.with(|s| s.auto_continue_mode) == AutoContinueMode::AutoContinue;
+                if auto_continue {
+                    // 次のセッションがそのまま始まるので終了時刻を更新する
+                    end_instant.set(Some(ended_at + new_duration as f64 * 1000.0));
+                    session_started_at.set(Some(ended_at));
+                } else {
+                    is_active.set(false);
+                    interval.set(None);
+                    awaiting_confirmation.set(true);
+                    end_instant.set(None);
+                    session_started_at.set(None);
+                }
+
+                persist_runtime();
+            }
+        };
+
+        // 1秒ごとにタイマーを更新
+        let new_interval = Interval::new(1000, timer_callback);
+        interval.set(Some(new_interval));
+    };
+
+    // リロード時に実行中だったセッションのインターバルを再開する
+    use_effect(move || {
+        if is_active() && interval.with(|i| i.is_none()) {
+            spawn_interval();
+        }
+    });
 
     // タイマーを開始する関数
     let mut start_timer = move || {
         if !is_active() {
             is_active.set(true);
+            awaiting_confirmation.set(false);
 
-            // 既存のインターバルがあれば解除
-            if interval.with(|i| i.is_some()) {
-                interval.set(None);
+            // このセッションの開始時刻をまだ記録していなければ記録する
+            if session_started_at.with(|s| s.is_none()) {
+                session_started_at.set(Some(js_sys::Date::now()));
             }
 
-            // 新しいインターバルを作成
-            let timer_callback = {
-                let mut seconds_remaining = seconds_remaining.clone();
-                let mut is_active = is_active.clone();
-                let mut mode = mode.clone();
-                let mut sessions_completed = sessions_completed.clone();
-                let settings = settings.clone();
-                let mut interval = interval.clone();
-
-                move || {
-                    if seconds_remaining() <= 1 {
-                        // タイマー終了時の処理
-                        seconds_remaining.set(0);
-                        is_active.set(false);
-
-                        // インターバルを停止
-                        interval.set(None);
-
-                        // 次のモードに切り替え
-                        match mode() {
-                            TimerMode::Work => {
-                                let current_sessions = sessions_completed() + 1;
-                                sessions_completed.set(current_sessions);
-
-                                // 長い休憩の条件を満たしているかチェック
-                                if current_sessions
-                                    % settings.with(|s| s.sessions_before_long_break)
-                                    == 0
-                                {
-                                    mode.set(TimerMode::LongBreak);
-                                    seconds_remaining
-                                        .set(settings.with(|s| s.long_break_minutes) * 60);
-                                } else {
-                                    mode.set(TimerMode::ShortBreak);
-                                    seconds_remaining
-                                        .set(settings.with(|s| s.short_break_minutes) * 60);
-                                }
-                            }
-                            TimerMode::ShortBreak | TimerMode::LongBreak => {
-                                mode.set(TimerMode::Work);
-                                seconds_remaining.set(settings.with(|s| s.work_minutes) * 60);
-                            }
-                        }
-                    } else {
-                        // カウントダウン
-                        seconds_remaining.set(seconds_remaining() - 1);
-                    }
-                }
-            };
+            // 残り時間から、このセッションが終わる壁時計上の時刻を算出する
+            end_instant.set(Some(
+                js_sys::Date::now() + seconds_remaining() as f64 * 1000.0,
+            ));
 
-            // 1秒ごとにタイマーを更新
-            let new_interval = Interval::new(1000, timer_callback);
-            interval.set(Some(new_interval));
+            spawn_interval();
+            persist_runtime();
         }
     };
 
@@ -133,6 +546,8 @@ pub fn PomodoroTimer() -> Element {
         if is_active() {
             is_active.set(false);
             interval.set(None);
+            end_instant.set(None);
+            persist_runtime();
         }
     };
 
@@ -140,17 +555,14 @@ pub fn PomodoroTimer() -> Element {
     let mut reset_timer = move || {
         is_active.set(false);
         interval.set(None);
+        pre_warning_sent.set(false);
+        awaiting_confirmation.set(false);
+        session_started_at.set(None);
+        end_instant.set(None);
 
         // 現在のモードに応じた時間にリセット
-        match mode() {
-            TimerMode::Work => seconds_remaining.set(settings.with(|s| s.work_minutes) * 60),
-            TimerMode::ShortBreak => {
-                seconds_remaining.set(settings.with(|s| s.short_break_minutes) * 60)
-            }
-            TimerMode::LongBreak => {
-                seconds_remaining.set(settings.with(|s| s.long_break_minutes) * 60)
-            }
-        }
+        seconds_remaining.set(settings.with(|s| duration_for_mode(s, &mode())));
+        persist_runtime();
     };
 
     // モードを変更する関数
@@ -159,17 +571,14 @@ pub fn PomodoroTimer() -> Element {
             mode.set(new_mode.clone());
             is_active.set(false);
             interval.set(None);
+            pre_warning_sent.set(false);
+            awaiting_confirmation.set(false);
+            session_started_at.set(None);
+            end_instant.set(None);
 
             // 新しいモードに応じた時間を設定
-            match new_mode {
-                TimerMode::Work => seconds_remaining.set(settings.with(|s| s.work_minutes) * 60),
-                TimerMode::ShortBreak => {
-                    seconds_remaining.set(settings.with(|s| s.short_break_minutes) * 60)
-                }
-                TimerMode::LongBreak => {
-                    seconds_remaining.set(settings.with(|s| s.long_break_minutes) * 60)
-                }
-            }
+            seconds_remaining.set(settings.with(|s| duration_for_mode(s, &new_mode)));
+            persist_runtime();
         }
     };
 
@@ -179,15 +588,23 @@ pub fn PomodoroTimer() -> Element {
     let time_display = format!("{:02}:{:02}", minutes, seconds);
 
     // モードに応じたラベルを取得
-    let mode_label = match mode() {
-        TimerMode::Work => "仕事",
-        TimerMode::ShortBreak => "短い休憩",
-        TimerMode::LongBreak => "長い休憩",
-    };
+    let current_mode_label = mode_label(&mode());
 
     rsx! {
         div { class: "pomodoro-container",
-            h2 { "ポモドーロタイマー" }
+            div { class: "pomodoro-header",
+                h2 { "ポモドーロタイマー" }
+                button {
+                    class: "settings-toggle-button",
+                    "aria-label": "設定",
+                    onclick: move |_| show_settings.set(!show_settings()),
+                    "⚙"
+                }
+            }
+
+            if show_settings() {
+                SettingsPanel { settings, mode, seconds_remaining, is_active }
+            }
 
             // モード選択ボタン
             div { class: "mode-buttons",
@@ -212,7 +629,24 @@ pub fn PomodoroTimer() -> Element {
             div { class: "timer-display", "{time_display}" }
 
             // 現在のモード表示
-            p { class: "current-mode", "現在のモード: {mode_label}" }
+            p { class: "current-mode", "現在のモード: {current_mode_label}" }
+
+            // 「確認」モードでのセッション開始確認プロンプト
+            if awaiting_confirmation() {
+                div { class: "confirm-next-session",
+                    p { "次のセッションを始めますか?" }
+                    button {
+                        class: "timer-button confirm-yes-button",
+                        onclick: move |_| start_timer(),
+                        "はい"
+                    }
+                    button {
+                        class: "timer-button confirm-no-button",
+                        onclick: move |_| awaiting_confirmation.set(false),
+                        "いいえ"
+                    }
+                }
+            }
 
             // タイマー制御ボタン
             div { class: "timer-controls",
@@ -239,6 +673,206 @@ pub fn PomodoroTimer() -> Element {
             div { class: "session-info",
                 p { "完了したセッション: {sessions_completed}" }
             }
+
+            // セッション履歴
+            HistoryPanel {
+                history,
+                sessions_before_long_break: settings.with(|s| s.sessions_before_long_break),
+            }
+        }
+    }
+}
+
+// セッション履歴パネル。今日 / 全期間を切り替えて完了した作業セッション数と合計集中時間を表示する
+#[component]
+fn HistoryPanel(history: Signal<Vec<SessionRecord>>, sessions_before_long_break: u32) -> Element {
+    let mut filter = use_signal(|| HistoryFilter::Today);
+
+    let filtered_work_records = history.with(|records| {
+        records
+            .iter()
+            .filter(|record| record.mode == TimerMode::Work)
+            .filter(|record| match filter() {
+                HistoryFilter::Today => is_today(record.ended_at),
+                HistoryFilter::AllTime => true,
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    });
+
+    let completed_work_sessions = filtered_work_records.len();
+    let total_focused_seconds: f64 = filtered_work_records
+        .iter()
+        .map(|record| (record.ended_at - record.started_at) / 1000.0)
+        .sum();
+    let total_focused_minutes = (total_focused_seconds / 60.0).round() as u64;
+
+    // 表示中の絞り込み(今日 / 全期間)と揃えるため、完了した作業セッション数から算出する
+    let full_cycles = if sessions_before_long_break > 0 {
+        completed_work_sessions as u32 / sessions_before_long_break
+    } else {
+        0
+    };
+
+    rsx! {
+        div { class: "history-panel",
+            h3 { "セッション履歴" }
+            div { class: "history-filter",
+                button {
+                    class: if filter() == HistoryFilter::Today { "history-filter-button active" } else { "history-filter-button" },
+                    onclick: move |_| filter.set(HistoryFilter::Today),
+                    "今日"
+                }
+                button {
+                    class: if filter() == HistoryFilter::AllTime { "history-filter-button active" } else { "history-filter-button" },
+                    onclick: move |_| filter.set(HistoryFilter::AllTime),
+                    "全期間"
+                }
+            }
+            p { "完了した作業セッション: {completed_work_sessions}" }
+            p { "合計集中時間: {total_focused_minutes}分" }
+            p { "完了したサイクル: {full_cycles}" }
+        }
+    }
+}
+
+// 設定パネル。各項目の入力を編集すると即座に localStorage へ保存する
+#[component]
+fn SettingsPanel(
+    mut settings: Signal<TimerSettings>,
+    mode: Signal<TimerMode>,
+    mut seconds_remaining: Signal<u32>,
+    is_active: Signal<bool>,
+) -> Element {
+    // 設定変更後、アイドル中であれば現在のモードの残り時間を再計算する
+    let mut apply_change = move |update: impl FnOnce(&mut TimerSettings)| {
+        settings.with_mut(update);
+        save_settings(&settings());
+        if !is_active() {
+            seconds_remaining.set(settings.with(|s| duration_for_mode(s, &mode())));
+            save_runtime_state(&TimerRuntimeState {
+                mode: mode(),
+                is_active: false,
+                end_instant: None,
+                seconds_remaining: seconds_remaining(),
+            });
+        }
+    };
+
+    rsx! {
+        div { class: "settings-panel",
+            h3 { "設定" }
+            DurationField {
+                label: "作業時間",
+                seconds: settings.with(|s| s.work_seconds),
+                on_change: move |value| apply_change(move |s| s.work_seconds = value),
+            }
+            DurationField {
+                label: "短い休憩",
+                seconds: settings.with(|s| s.short_break_seconds),
+                on_change: move |value| apply_change(move |s| s.short_break_seconds = value),
+            }
+            DurationField {
+                label: "長い休憩",
+                seconds: settings.with(|s| s.long_break_seconds),
+                on_change: move |value| apply_change(move |s| s.long_break_seconds = value),
+            }
+            label { class: "settings-field",
+                "長い休憩までのセッション数"
+                input {
+                    r#type: "number",
+                    min: "1",
+                    value: "{settings.with(|s| s.sessions_before_long_break)}",
+                    oninput: move |evt| {
+                        if let Ok(value) = evt.value().parse::<u32>() {
+                            // 0 は周期計算の剰余・除算でパニックするため拒否する
+                            if value >= 1 {
+                                apply_change(move |s| s.sessions_before_long_break = value);
+                            }
+                        }
+                    },
+                }
+            }
+            label { class: "settings-field settings-field-checkbox",
+                input {
+                    r#type: "checkbox",
+                    checked: settings.with(|s| s.notifications_enabled),
+                    oninput: move |evt| apply_change(move |s| s.notifications_enabled = evt.checked()),
+                }
+                "セッション終了時に通知する"
+            }
+            label { class: "settings-field settings-field-checkbox",
+                input {
+                    r#type: "checkbox",
+                    checked: settings.with(|s| s.sound_enabled),
+                    oninput: move |evt| apply_change(move |s| s.sound_enabled = evt.checked()),
+                }
+                "セッション終了時にチャイムを鳴らす"
+            }
+            label { class: "settings-field",
+                "終了前に予告通知(分、0で無効)"
+                input {
+                    r#type: "number",
+                    min: "0",
+                    value: "{settings.with(|s| s.notify_before_minutes)}",
+                    oninput: move |evt| {
+                        if let Ok(value) = evt.value().parse::<u32>() {
+                            apply_change(move |s| s.notify_before_minutes = value);
+                        }
+                    },
+                }
+            }
+            label { class: "settings-field",
+                "セッション終了後の挙動"
+                select {
+                    value: match settings.with(|s| s.auto_continue_mode) {
+                        AutoContinueMode::AutoContinue => "auto",
+                        AutoContinueMode::Confirm => "confirm",
+                    },
+                    onchange: move |evt| {
+                        let mode = match evt.value().as_str() {
+                            "auto" => AutoContinueMode::AutoContinue,
+                            _ => AutoContinueMode::Confirm,
+                        };
+                        apply_change(move |s| s.auto_continue_mode = mode);
+                    },
+                    option { value: "confirm", "確認してから開始" }
+                    option { value: "auto", "自動で継続" }
+                }
+            }
+        }
+    }
+}
+
+// 時間指定文字列("1h30m" / "90s" / "25m" など)を受け付ける入力欄。
+// パースに失敗した場合は直前まで有効だった値を保持し、エラーを表示する
+#[component]
+fn DurationField(label: &'static str, seconds: u32, on_change: EventHandler<u32>) -> Element {
+    let mut text = use_signal(|| format_duration(seconds));
+    let mut error = use_signal(|| None::<String>);
+
+    rsx! {
+        label { class: "settings-field",
+            "{label}"
+            input {
+                r#type: "text",
+                placeholder: "例: 25m, 1h30m, 90s",
+                value: "{text}",
+                oninput: move |evt| {
+                    let value = evt.value();
+                    text.set(value.clone());
+                    match parse_duration(&value) {
+                        Ok(parsed_seconds) => {
+                            error.set(None);
+                            on_change.call(parsed_seconds);
+                        }
+                        Err(message) => error.set(Some(message)),
+                    }
+                },
+            }
+            if let Some(message) = error() {
+                span { class: "settings-field-error", "{message}" }
+            }
         }
     }
 }